@@ -3,6 +3,7 @@
 
 #![deny(unsafe_code)]
 #![cfg_attr(not(any(test)), no_std)]
+#![cfg_attr(feature = "step_trait", feature(step_trait))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![cfg_attr(docsrs, feature(doc_cfg_hide))]
 #![cfg_attr(docsrs, doc(cfg_hide(docsrs)))]
@@ -12,7 +13,6 @@
 /// Four-Octet ASN as per [RFC 6793](https://datatracker.ietf.org/doc/html/rfc6793)
 #[repr(transparent)]
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
-// TODO: serde Serialize, Deserialize with feature
 pub struct Asn(u32);
 
 impl Asn {
@@ -25,28 +25,37 @@ impl Asn {
     /// Reserved "Last" 32-bit ASN as per [RFC 7300](https://datatracker.ietf.org/doc/html/rfc7300)
     pub const LAST4: Self = Self::new(4294967295);
 
+    // Inclusive `(start, end)` bounds backing the `RESERVED_*` ranges below. Factored out so the
+    // range constants and the `is_reserved_*` predicates share a single source of truth, since
+    // `RangeInclusive::start`/`end` and `contains` are not usable from a `const fn`.
+    const DOCUMENTATION_BOUNDS: (u32, u32) = (64496, 64511);
+    const DOCUMENTATION4_BOUNDS: (u32, u32) = (65536, 65551);
+    const PRIVATE_BOUNDS: (u32, u32) = (64512, 65534);
+    const PRIVATE4_BOUNDS: (u32, u32) = (4200000000, 4294967294);
+    const IANA4_BOUNDS: (u32, u32) = (65552, 131071);
+
     /// Reserved for documentation use (16-bit number set) as per [RFC 5398](https://datatracker.ietf.org/doc/html/rfc5398)
     pub const RESERVED_DOCUMENTATION: core::ops::RangeInclusive<Self> =
-        (Self::new(64496)..=Self::new(64511));
+        (Self::new(Self::DOCUMENTATION_BOUNDS.0)..=Self::new(Self::DOCUMENTATION_BOUNDS.1));
 
     /// Reserved for documentation use (32-bit number set) as per [RFC 5398](https://datatracker.ietf.org/doc/html/rfc5398)
     pub const RESERVED_DOCUMENTATION4: core::ops::RangeInclusive<Self> =
-        (Self::new(65536)..=Self::new(65551));
+        (Self::new(Self::DOCUMENTATION4_BOUNDS.0)..=Self::new(Self::DOCUMENTATION4_BOUNDS.1));
 
     /// Reserved for private use (16-bit ASNs) as per [RFC 6996](https://datatracker.ietf.org/doc/html/rfc6996)
     pub const RESERVED_PRIVATE: core::ops::RangeInclusive<Self> =
-        (Self::new(64512)..=Self::new(65534));
+        (Self::new(Self::PRIVATE_BOUNDS.0)..=Self::new(Self::PRIVATE_BOUNDS.1));
 
     /// Reserved for private use (32-bit ASNs) as per [RFC 6996](https://datatracker.ietf.org/doc/html/rfc6996)
     pub const RESERVED_PRIVATE4: core::ops::RangeInclusive<Self> =
-        (Self::new(4200000000)..=Self::new(4294967294));
+        (Self::new(Self::PRIVATE4_BOUNDS.0)..=Self::new(Self::PRIVATE4_BOUNDS.1));
 
     /// Reserved to represent non-mappable four-octet AS numbers as two-octet AS numbers as per [RFC 6793](https://datatracker.ietf.org/doc/html/rfc6793)
     pub const TRANS: Self = Self::new(23456);
 
     /// Reserved as per [IANA 32-bit ASNs](https://www.iana.org/assignments/as-numbers/as-numbers.xhtml)
     pub const RESERVED_IANA4: core::ops::RangeInclusive<Self> =
-        (Self::new(65552)..=Self::new(131071));
+        (Self::new(Self::IANA4_BOUNDS.0)..=Self::new(Self::IANA4_BOUNDS.1));
 
     #[inline]
     pub const fn new(asn: u32) -> Self {
@@ -55,7 +64,35 @@ impl Asn {
 
     pub const fn from_str(src: &str) -> Result<Self, ParseAsnError> {
         if src.is_empty() {
-            return Err(ParseAsnError());
+            return Err(ParseAsnError::new(ParseAsnErrorKind::Empty));
+        }
+
+        // "asdot"/"asdot+" notation per RFC 5396 §2: a single `.` splits the value into a
+        // high and low 16-bit half. More than one dot is not a valid representation.
+        {
+            let bytes = src.as_bytes();
+            let mut dot = None;
+            let mut i = 0;
+            while i < bytes.len() {
+                if bytes[i] == b'.' {
+                    if dot.is_some() {
+                        return Err(ParseAsnError::new(ParseAsnErrorKind::InvalidDotNotation));
+                    }
+                    dot = Some(i);
+                }
+                i += 1;
+            }
+            if let Some(idx) = dot {
+                let high = match Self::parse_u16_range(bytes, 0, idx) {
+                    Ok(value) => value,
+                    Err(err) => return Err(err),
+                };
+                let low = match Self::parse_u16_range(bytes, idx + 1, bytes.len()) {
+                    Ok(value) => value,
+                    Err(err) => return Err(err),
+                };
+                return Ok(Self(high as u32 * 65536 + low as u32));
+            }
         }
 
         // all valid digits are ascii, so we will just iterate over the utf8 bytes
@@ -68,10 +105,10 @@ impl Asn {
         let mut result = 0;
 
         macro_rules! unwrap_or_PAE {
-            ($option:expr) => {
+            ($option:expr, $kind:expr) => {
                 match $option {
                     Some(value) => value,
-                    None => return Err(ParseAsnError()),
+                    None => return Err(ParseAsnError::new($kind)),
                 }
             };
         }
@@ -92,7 +129,7 @@ impl Asn {
             // `i8::MAX` is `7f` - only a str of len 1 is guaranteed to not overflow.
             while let [c, rest @ ..] = digits {
                 result *= 10_u32;
-                let x = unwrap_or_PAE!((*c as char).to_digit(10));
+                let x = unwrap_or_PAE!((*c as char).to_digit(10), ParseAsnErrorKind::InvalidDigit);
                 result += x;
                 digits = rest;
             }
@@ -109,29 +146,184 @@ impl Asn {
                 // doing multiplication first and let the CPU spends other cycles
                 // doing other computation and get multiplication result later.
                 let mul = result.checked_mul(10_u32);
-                let x = unwrap_or_PAE!((*c as char).to_digit(10));
-                result = unwrap_or_PAE!(mul);
-                result = unwrap_or_PAE!(u32::checked_add(result, x));
+                let x = unwrap_or_PAE!((*c as char).to_digit(10), ParseAsnErrorKind::InvalidDigit);
+                result = unwrap_or_PAE!(mul, ParseAsnErrorKind::PosOverflow);
+                result = unwrap_or_PAE!(
+                    u32::checked_add(result, x),
+                    ParseAsnErrorKind::PosOverflow
+                );
                 digits = rest;
             }
         }
         Ok(Self(result))
     }
 
-    // TODO: pub const fn is_reserved_last ?
+    /// Parses the half-open byte range `[start, end)` as a single asdot half into a [`u16`],
+    /// checking the bound after every digit. This loop is separate from the one in
+    /// [`Asn::from_str`]. Empty halves and halves exceeding [`u16::MAX`] are rejected.
+    const fn parse_u16_range(bytes: &[u8], start: usize, end: usize) -> Result<u16, ParseAsnError> {
+        if start >= end {
+            return Err(ParseAsnError::new(ParseAsnErrorKind::InvalidDotNotation));
+        }
+        let mut result: u32 = 0;
+        let mut i = start;
+        while i < end {
+            result *= 10_u32;
+            let x = match (bytes[i] as char).to_digit(10) {
+                Some(value) => value,
+                None => return Err(ParseAsnError::new(ParseAsnErrorKind::InvalidDigit)),
+            };
+            result += x;
+            if result > u16::MAX as u32 {
+                return Err(ParseAsnError::new(ParseAsnErrorKind::PosOverflow));
+            }
+            i += 1;
+        }
+        Ok(result as u16)
+    }
+
+    /// Returns [`true`] for the reserved "Last" ASNs as per [RFC 7300](https://datatracker.ietf.org/doc/html/rfc7300)
+    ///
+    /// This covers both the 16-bit [`Asn::LAST`] and the 32-bit [`Asn::LAST4`] number.
+    #[inline]
+    pub const fn is_reserved_last(&self) -> bool {
+        self.0 == Asn::LAST.0 || self.0 == Asn::LAST4.0
+    }
+
+    /// Returns [`true`] for the ASNs reserved for documentation use as per [RFC 5398](https://datatracker.ietf.org/doc/html/rfc5398)
+    ///
+    /// This covers both the 16-bit [`Asn::RESERVED_DOCUMENTATION`] and the 32-bit [`Asn::RESERVED_DOCUMENTATION4`] number set.
+    #[inline]
+    pub const fn is_reserved_documentation(&self) -> bool {
+        (self.0 >= Self::DOCUMENTATION_BOUNDS.0 && self.0 <= Self::DOCUMENTATION_BOUNDS.1)
+            || (self.0 >= Self::DOCUMENTATION4_BOUNDS.0 && self.0 <= Self::DOCUMENTATION4_BOUNDS.1)
+    }
+
+    /// Returns [`true`] for the ASNs reserved for private use as per [RFC 6996](https://datatracker.ietf.org/doc/html/rfc6996)
+    ///
+    /// This covers both the 16-bit [`Asn::RESERVED_PRIVATE`] and the 32-bit [`Asn::RESERVED_PRIVATE4`] number set.
+    #[inline]
+    pub const fn is_reserved_private(&self) -> bool {
+        (self.0 >= Self::PRIVATE_BOUNDS.0 && self.0 <= Self::PRIVATE_BOUNDS.1)
+            || (self.0 >= Self::PRIVATE4_BOUNDS.0 && self.0 <= Self::PRIVATE4_BOUNDS.1)
+    }
+
+    /// Returns [`true`] for the reserved [`Asn::TRANS`] number as per [RFC 6793](https://datatracker.ietf.org/doc/html/rfc6793)
+    #[inline]
+    pub const fn is_trans(&self) -> bool {
+        self.0 == Asn::TRANS.0
+    }
+
+    /// Returns [`true`] for the ASNs reserved as per [IANA 32-bit ASNs](https://www.iana.org/assignments/as-numbers/as-numbers.xhtml)
+    ///
+    /// This covers the 32-bit [`Asn::RESERVED_IANA4`] number set.
+    #[inline]
+    pub const fn is_reserved_iana(&self) -> bool {
+        self.0 >= Self::IANA4_BOUNDS.0 && self.0 <= Self::IANA4_BOUNDS.1
+    }
+
+    /// Returns [`true`] for globally routable ASNs, i.e. those not reserved for any special use.
+    #[inline]
+    pub const fn is_public(&self) -> bool {
+        self.0 != Asn::ZERO.0
+            && !self.is_trans()
+            && !self.is_reserved_documentation()
+            && !self.is_reserved_private()
+            && !self.is_reserved_iana()
+            && !self.is_reserved_last()
+    }
+
+    /// Returns the [`AsnScope`] category this ASN falls into, so a caller can branch on the
+    /// classification in a single match.
+    pub const fn classify(&self) -> AsnScope {
+        if self.0 == Asn::ZERO.0 {
+            AsnScope::Zero
+        } else if self.is_trans() {
+            AsnScope::Trans
+        } else if self.is_reserved_documentation() {
+            AsnScope::Documentation
+        } else if self.is_reserved_private() {
+            AsnScope::Private
+        } else if self.is_reserved_iana() {
+            AsnScope::IanaReserved
+        } else if self.is_reserved_last() {
+            AsnScope::Last
+        } else {
+            AsnScope::Public
+        }
+    }
+
+    /// The size of this ASN in bits, mirroring [`u32::BITS`].
+    pub const BITS: u32 = 32;
+
+    /// The smallest ASN, mirroring [`u32::MIN`].
+    pub const MIN: Self = Self::new(u32::MIN);
 
-    // TODO: pub const fn is_reserved_documentation ?
+    /// The largest ASN, mirroring [`u32::MAX`].
+    pub const MAX: Self = Self::new(u32::MAX);
 
-    // TODO: pub const fn is_reserved_private ?
+    /// Checked ASN addition, mirroring [`u32::checked_add`]. Returns [`None`] on overflow past
+    /// [`Asn::MAX`].
+    #[inline]
+    pub const fn checked_add(self, rhs: u32) -> Option<Self> {
+        match self.0.checked_add(rhs) {
+            Some(value) => Some(Self::new(value)),
+            None => None,
+        }
+    }
+
+    /// Checked ASN subtraction, mirroring [`u32::checked_sub`]. Returns [`None`] on overflow past
+    /// [`Asn::MIN`].
+    #[inline]
+    pub const fn checked_sub(self, rhs: u32) -> Option<Self> {
+        match self.0.checked_sub(rhs) {
+            Some(value) => Some(Self::new(value)),
+            None => None,
+        }
+    }
 
-    // TODO: pub const fn is_reserved_trans ?
+    /// Saturating ASN addition, mirroring [`u32::saturating_add`]. Saturates at [`Asn::MAX`].
+    #[inline]
+    pub const fn saturating_add(self, rhs: u32) -> Self {
+        Self::new(self.0.saturating_add(rhs))
+    }
 
-    // TODO: pub const fn is_reserved_iana ? (reserved4 or just reserved would clash with a fn encompassing the other reservations)
+    /// Saturating ASN subtraction, mirroring [`u32::saturating_sub`]. Saturates at [`Asn::MIN`].
+    #[inline]
+    pub const fn saturating_sub(self, rhs: u32) -> Self {
+        Self::new(self.0.saturating_sub(rhs))
+    }
 
-    // TODO: pub const fn is_public ?
+    /// Returns [`Asn::TRANS`] when this value does not fit in a two-octet AS number, encoding the
+    /// AS_TRANS mapping rule of [RFC 6793](https://datatracker.ietf.org/doc/html/rfc6793), and the
+    /// value itself otherwise.
+    #[inline]
+    pub const fn to_trans_if_unmappable(self) -> Asn {
+        if self.0 > u16::MAX as u32 {
+            Asn::TRANS
+        } else {
+            self
+        }
+    }
+}
 
-    // TODO: pub const BITS
-    // TODO: pub const MIN, pub const MAX
+/// The category an [`Asn`] falls into, as returned by [`Asn::classify`]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum AsnScope {
+    /// A globally routable ASN not reserved for any special use
+    Public,
+    /// The reserved AS 0 (zero) number as per [RFC 7607](https://datatracker.ietf.org/doc/html/rfc7607)
+    Zero,
+    /// An ASN reserved for documentation use as per [RFC 5398](https://datatracker.ietf.org/doc/html/rfc5398)
+    Documentation,
+    /// An ASN reserved for private use as per [RFC 6996](https://datatracker.ietf.org/doc/html/rfc6996)
+    Private,
+    /// The reserved [`Asn::TRANS`] number as per [RFC 6793](https://datatracker.ietf.org/doc/html/rfc6793)
+    Trans,
+    /// An ASN reserved as per [IANA 32-bit ASNs](https://www.iana.org/assignments/as-numbers/as-numbers.xhtml)
+    IanaReserved,
+    /// A reserved "Last" ASN as per [RFC 7300](https://datatracker.ietf.org/doc/html/rfc7300)
+    Last,
 }
 
 // TODO: with reference core::convert::From<&Asn> for u32
@@ -152,7 +344,18 @@ impl core::convert::From<u32> for Asn {
     }
 }
 
-// TODO: core::convert::TryFrom<Asn> for u16
+impl core::convert::TryFrom<Asn> for u16 {
+    type Error = TryFromAsnError;
+
+    #[inline]
+    fn try_from(asn: Asn) -> Result<u16, Self::Error> {
+        if asn.0 > u16::MAX as u32 {
+            Err(TryFromAsnError())
+        } else {
+            Ok(asn.0 as u16)
+        }
+    }
+}
 
 impl core::convert::From<u16> for Asn {
     #[inline]
@@ -176,16 +379,195 @@ impl core::fmt::Display for Asn {
     }
 }
 
-// TODO: "asdot+" core::fmt::Display
-// TODO: "asdot" core::fmt::Display
 // https://doc.rust-lang.org/std/fmt/trait.Display.html#internationalization
 // https://datatracker.ietf.org/doc/html/rfc5396#section-2
 
-// TODO: nightly core::iter::Step for Asn
+/// Formatting wrapper emitting the "asdot" representation of an [`Asn`] as per [RFC 5396](https://datatracker.ietf.org/doc/html/rfc5396)
+///
+/// Values up to `65535` are printed as plain decimal, larger values as `high.low`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct AsDot(pub Asn);
+
+impl core::fmt::Display for AsDot {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let value = (self.0).0;
+        if value <= 65535 {
+            write!(f, "{}", value)
+        } else {
+            write!(f, "{}.{}", value / 65536, value % 65536)
+        }
+    }
+}
+
+/// Formatting wrapper emitting the "asdot+" representation of an [`Asn`] as per [RFC 5396](https://datatracker.ietf.org/doc/html/rfc5396)
+///
+/// Always printed as `high.low`, including for values up to `65535`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct AsDotPlus(pub Asn);
+
+impl core::fmt::Display for AsDotPlus {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let value = (self.0).0;
+        write!(f, "{}.{}", value / 65536, value % 65536)
+    }
+}
+
+/// Allows iterating over `RangeInclusive<Asn>` (e.g. the `RESERVED_*` ranges) by delegating
+/// to the inner [`u32`]'s [`Step`](core::iter::Step) implementation.
+///
+/// Requires a nightly compiler as [`core::iter::Step`] is unstable.
+#[cfg(feature = "step_trait")]
+#[cfg_attr(docsrs, doc(cfg(feature = "step_trait")))]
+impl core::iter::Step for Asn {
+    #[inline]
+    fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+        core::iter::Step::steps_between(&start.0, &end.0)
+    }
+
+    #[inline]
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        core::iter::Step::forward_checked(start.0, count).map(Self::new)
+    }
+
+    #[inline]
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        core::iter::Step::backward_checked(start.0, count).map(Self::new)
+    }
+}
+
+/// Serializes to the numeric [`u32`] value ("asplain"), matching the default [`Display`](core::fmt::Display). To emit
+/// the string/asdot form instead, serialize via the [`AsDot`]/[`AsDotPlus`] wrapper types.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for Asn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u32(self.0)
+    }
+}
+
+/// Serializes the wrapped [`Asn`] as its "asdot" string form (e.g. `"1.10"`), providing the
+/// string-representation choice on the serialize side. Deserializes back through the
+/// asplain/asdot-aware [`Asn`] deserializer.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for AsDot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for AsDot {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(AsDot(Asn::deserialize(deserializer)?))
+    }
+}
+
+/// Serializes the wrapped [`Asn`] as its "asdot+" string form (e.g. `"1.10"`), providing the
+/// string-representation choice on the serialize side. Deserializes back through the
+/// asplain/asdot-aware [`Asn`] deserializer.
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl serde::Serialize for AsDotPlus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for AsDotPlus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Ok(AsDotPlus(Asn::deserialize(deserializer)?))
+    }
+}
+
+/// Deserializes from either a numeric value or an asplain/asdot string (e.g. `65546` or `"1.10"`).
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl<'de> serde::Deserialize<'de> for Asn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct AsnVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for AsnVisitor {
+            type Value = Asn;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str("an ASN as a 32-bit integer or an asplain/asdot string")
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Asn, E>
+            where
+                E: serde::de::Error,
+            {
+                if value > u32::MAX as u64 {
+                    Err(E::custom("ASN out of range"))
+                } else {
+                    Ok(Asn::new(value as u32))
+                }
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Asn, E>
+            where
+                E: serde::de::Error,
+            {
+                if value < 0 || value > u32::MAX as i64 {
+                    Err(E::custom("ASN out of range"))
+                } else {
+                    Ok(Asn::new(value as u32))
+                }
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Asn, E>
+            where
+                E: serde::de::Error,
+            {
+                Asn::from_str(value).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(AsnVisitor)
+    }
+}
 
 /// Error which can be returned when parsing an [`Asn`]
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ParseAsnError();
+pub struct ParseAsnError {
+    kind: ParseAsnErrorKind,
+}
+
+impl ParseAsnError {
+    #[inline]
+    const fn new(kind: ParseAsnErrorKind) -> Self {
+        Self { kind }
+    }
+
+    /// Returns the detailed cause of this parse failure, mirroring
+    /// [`core::num::IntErrorKind`] for integer parsing.
+    #[inline]
+    pub const fn kind(&self) -> ParseAsnErrorKind {
+        self.kind
+    }
+}
 
 impl core::fmt::Display for ParseAsnError {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
@@ -195,6 +577,35 @@ impl core::fmt::Display for ParseAsnError {
 
 impl core::error::Error for ParseAsnError {}
 
+/// Error which can be returned when converting an [`Asn`] into a two-octet AS number, mirroring
+/// [`core::num::TryFromIntError`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryFromAsnError();
+
+impl core::fmt::Display for TryFromAsnError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "ASN out of range for two-octet AS number")
+    }
+}
+
+impl core::error::Error for TryFromAsnError {}
+
+/// Enum storing the detailed cause carried by a [`ParseAsnError`], mirroring
+/// [`core::num::IntErrorKind`](https://doc.rust-lang.org/core/num/enum.IntErrorKind.html)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseAsnErrorKind {
+    /// The input was empty.
+    Empty,
+    /// A byte in the input was not a valid digit.
+    InvalidDigit,
+    /// The value was too large to fit in a 32-bit ASN, or one half of an asdot/asdot+ value
+    /// was larger than `65535`.
+    PosOverflow,
+    /// The input used malformed asdot/asdot+ notation (extra dot, empty half).
+    InvalidDotNotation,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -231,6 +642,33 @@ mod tests {
         let _: Asn = 0_u16.into();
     }
 
+    #[test]
+    fn test_u16_try_from_asn() {
+        use core::convert::TryFrom;
+        assert_eq!(u16::try_from(Asn::new(65535)), Ok(65535_u16));
+        assert_eq!(u16::try_from(Asn::new(65536)).unwrap_err(), TryFromAsnError());
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        assert_eq!(Asn::new(1).checked_add(1), Some(Asn::new(2)));
+        assert_eq!(Asn::MAX.checked_add(1), None);
+        assert_eq!(Asn::new(1).checked_sub(1), Some(Asn::ZERO));
+        assert_eq!(Asn::MIN.checked_sub(1), None);
+    }
+
+    #[test]
+    fn test_saturating_arithmetic() {
+        assert_eq!(Asn::MAX.saturating_add(1), Asn::MAX);
+        assert_eq!(Asn::MIN.saturating_sub(1), Asn::MIN);
+    }
+
+    #[test]
+    fn test_to_trans_if_unmappable() {
+        assert_eq!(Asn::new(65535).to_trans_if_unmappable(), Asn::new(65535));
+        assert_eq!(Asn::new(65536).to_trans_if_unmappable(), Asn::TRANS);
+    }
+
     #[test]
     fn test_debug() {
         assert_eq!(format!("{:?}", Asn::new(0)), "Asn(0)");
@@ -248,6 +686,13 @@ mod tests {
         assert_eq!(format!("{}", Asn::new(65546)), "65546");
     }
 
+    #[test]
+    fn test_bits_min_max() {
+        assert_eq!(Asn::BITS, 32);
+        assert_eq!(Asn::MIN, Asn::new(u32::MIN));
+        assert_eq!(Asn::MAX, Asn::new(u32::MAX));
+    }
+
     #[test]
     fn test_last_eq() {
         assert_eq!(Asn::LAST, Asn::new(65535));
@@ -276,17 +721,124 @@ mod tests {
         assert!(Asn::RESERVED_IANA4.contains(&Asn::new(100000)));
     }
 
+    #[test]
+    fn test_is_reserved_last() {
+        assert!(Asn::LAST.is_reserved_last());
+        assert!(Asn::LAST4.is_reserved_last());
+        assert!(!Asn::new(1).is_reserved_last());
+    }
+
+    #[test]
+    fn test_is_reserved_documentation() {
+        assert!(Asn::new(64500).is_reserved_documentation());
+        assert!(Asn::new(65540).is_reserved_documentation());
+        assert!(!Asn::new(1).is_reserved_documentation());
+    }
+
+    #[test]
+    fn test_is_reserved_private() {
+        assert!(Asn::new(64520).is_reserved_private());
+        assert!(Asn::new(4242424242).is_reserved_private());
+        assert!(!Asn::new(1).is_reserved_private());
+    }
+
+    #[test]
+    fn test_is_trans() {
+        assert!(Asn::TRANS.is_trans());
+        assert!(!Asn::new(1).is_trans());
+    }
+
+    #[test]
+    fn test_is_reserved_iana() {
+        assert!(Asn::new(100000).is_reserved_iana());
+        assert!(!Asn::new(1).is_reserved_iana());
+    }
+
+    #[test]
+    fn test_is_public() {
+        assert!(Asn::new(1).is_public());
+        assert!(!Asn::ZERO.is_public());
+        assert!(!Asn::TRANS.is_public());
+        assert!(!Asn::new(64500).is_public());
+    }
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(Asn::new(1).classify(), AsnScope::Public);
+        assert_eq!(Asn::ZERO.classify(), AsnScope::Zero);
+        assert_eq!(Asn::new(64500).classify(), AsnScope::Documentation);
+        assert_eq!(Asn::new(64520).classify(), AsnScope::Private);
+        assert_eq!(Asn::TRANS.classify(), AsnScope::Trans);
+        assert_eq!(Asn::new(100000).classify(), AsnScope::IanaReserved);
+        assert_eq!(Asn::LAST.classify(), AsnScope::Last);
+    }
+
     #[test]
     fn test_from_str() -> Result<(), Box<dyn std::error::Error>> {
         // https://datatracker.ietf.org/doc/html/rfc5396#section-2
         assert_eq!(Asn::from_str("65526")?, Asn::new(65526));
         assert_eq!(Asn::from_str("65546")?, Asn::new(65546));
 
-        assert_eq!(Asn::from_str("hurz").unwrap_err(), ParseAsnError());
+        assert_eq!(
+            Asn::from_str("hurz").unwrap_err().kind(),
+            ParseAsnErrorKind::InvalidDigit
+        );
 
         Ok(())
     }
 
+    #[test]
+    fn test_from_str_asdot() -> Result<(), Box<dyn std::error::Error>> {
+        // https://datatracker.ietf.org/doc/html/rfc5396#section-2
+        assert_eq!(Asn::from_str("1.10")?, Asn::new(65546));
+        assert_eq!(Asn::from_str("0.65526")?, Asn::new(65526));
+
+        assert_eq!(
+            Asn::from_str("1.2.3").unwrap_err().kind(),
+            ParseAsnErrorKind::InvalidDotNotation
+        );
+        assert_eq!(
+            Asn::from_str("1.").unwrap_err().kind(),
+            ParseAsnErrorKind::InvalidDotNotation
+        );
+        assert_eq!(
+            Asn::from_str(".1").unwrap_err().kind(),
+            ParseAsnErrorKind::InvalidDotNotation
+        );
+        assert_eq!(
+            Asn::from_str("1.65536").unwrap_err().kind(),
+            ParseAsnErrorKind::PosOverflow
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_display_asdot() {
+        // https://datatracker.ietf.org/doc/html/rfc5396#section-2
+        assert_eq!(format!("{}", AsDot(Asn::new(65526))), "65526");
+        assert_eq!(format!("{}", AsDot(Asn::new(65546))), "1.10");
+    }
+
+    #[test]
+    fn test_display_asdotplus() {
+        // https://datatracker.ietf.org/doc/html/rfc5396#section-2
+        assert_eq!(format!("{}", AsDotPlus(Asn::new(65526))), "0.65526");
+        assert_eq!(format!("{}", AsDotPlus(Asn::new(65546))), "1.10");
+    }
+
+    #[test]
+    fn test_parseasnerror_kind() {
+        assert_eq!(
+            Asn::from_str("").unwrap_err().kind(),
+            ParseAsnErrorKind::Empty
+        );
+        assert_eq!(
+            Asn::from_str("99999999999").unwrap_err().kind(),
+            ParseAsnErrorKind::PosOverflow
+        );
+    }
+
     #[test]
     fn test_parseasnerror_display() {
         assert_eq!(
@@ -299,4 +851,52 @@ mod tests {
     fn test_fromstr() {
         assert_eq!("65526".parse(), Ok(Asn::new(65526)));
     }
+
+    #[cfg(feature = "step_trait")]
+    #[test]
+    fn test_step_iterate_range() {
+        let asns: Vec<Asn> = (Asn::new(64496)..=Asn::new(64498)).collect();
+        assert_eq!(
+            asns,
+            vec![Asn::new(64496), Asn::new(64497), Asn::new(64498)]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_serialize() {
+        assert_eq!(serde_json::to_string(&Asn::new(65546)).unwrap(), "65546");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_serialize_asdot() {
+        assert_eq!(serde_json::to_string(&AsDot(Asn::new(65546))).unwrap(), "\"1.10\"");
+        assert_eq!(
+            serde_json::to_string(&AsDotPlus(Asn::new(65526))).unwrap(),
+            "\"0.65526\""
+        );
+        assert_eq!(
+            serde_json::from_str::<AsDot>("\"1.10\"").unwrap(),
+            AsDot(Asn::new(65546))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_deserialize() {
+        // https://datatracker.ietf.org/doc/html/rfc5396#section-2
+        assert_eq!(
+            serde_json::from_str::<Asn>("65546").unwrap(),
+            Asn::new(65546)
+        );
+        assert_eq!(
+            serde_json::from_str::<Asn>("\"1.10\"").unwrap(),
+            Asn::new(65546)
+        );
+        assert_eq!(
+            serde_json::from_str::<Asn>("\"65546\"").unwrap(),
+            Asn::new(65546)
+        );
+    }
 }